@@ -1,4 +1,4 @@
-use adf::{parse_file, Result};
+use adf::{parse_with_options, ParseOptions, Result};
 use std::env;
 use std::process;
 
@@ -53,23 +53,35 @@ fn run() -> Result<()> {
 }
 
 fn check_file(path: &str) -> Result<()> {
-    let doc = parse_file(path)?;
-    println!("✓ Valid ADF document");
-    println!("  {} keys in root", doc.as_map().len());
-    Ok(())
+    let text = std::fs::read_to_string(path)?;
+    let options = ParseOptions {
+        validate_constraints: true,
+        ..ParseOptions::default()
+    };
+    match parse_with_options(&text, options) {
+        Ok(doc) => {
+            println!("✓ Valid ADF document");
+            println!("  {} keys in root", doc.as_map().len());
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", e.render(&text));
+            process::exit(1);
+        }
+    }
 }
 
-#[cfg(feature = "serde")]
+#[cfg(all(feature = "serde", not(feature = "bignum")))]
 fn to_json(path: &str) -> Result<()> {
-    let doc = parse_file(path)?;
+    let doc = adf::parse_file(path)?;
     let json = doc.to_json()?;
     println!("{}", json);
     Ok(())
 }
 
-#[cfg(not(feature = "serde"))]
+#[cfg(not(all(feature = "serde", not(feature = "bignum"))))]
 fn to_json(_path: &str) -> Result<()> {
-    eprintln!("Error: JSON support requires the 'serde' feature");
+    eprintln!("Error: JSON support requires the 'serde' feature (and is unavailable together with 'bignum')");
     eprintln!("Rebuild with: cargo build --features serde");
     process::exit(1);
 }
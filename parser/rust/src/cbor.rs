@@ -0,0 +1,221 @@
+//! A minimal CBOR (RFC 8949) codec for [`Value`] with a deterministic
+//! encoding, used by [`crate::Document::to_cbor`]/[`crate::Document::from_cbor`].
+//! Object keys are sorted by their string order (not the RFC's encoded-byte
+//! order) and floats are always written as 64-bit.
+
+use crate::error::{AdfError, Result};
+use crate::value::Value;
+use std::collections::HashMap;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_SIMPLE: u8 = 7;
+
+const SIMPLE_FALSE: u8 = 20;
+const SIMPLE_TRUE: u8 = 21;
+const SIMPLE_NULL: u8 = 22;
+const SIMPLE_F64: u8 = 27;
+
+/// Encode `value` as canonical CBOR: object keys are emitted in sorted order
+/// so the same `Value` always produces the same bytes.
+pub fn encode(value: &Value) -> Vec<u8> {
+    let mut out = Vec::new();
+    encode_into(value, &mut out);
+    out
+}
+
+fn encode_into(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Null => out.push((MAJOR_SIMPLE << 5) | SIMPLE_NULL),
+        Value::Boolean(false) => out.push((MAJOR_SIMPLE << 5) | SIMPLE_FALSE),
+        Value::Boolean(true) => out.push((MAJOR_SIMPLE << 5) | SIMPLE_TRUE),
+        Value::Integer(i) if *i >= 0 => write_uint(MAJOR_UNSIGNED, *i as u64, out),
+        Value::Integer(i) => write_uint(MAJOR_NEGATIVE, (-1 - *i) as u64, out),
+        Value::Float(f) => {
+            out.push((MAJOR_SIMPLE << 5) | SIMPLE_F64);
+            out.extend_from_slice(&f.to_be_bytes());
+        }
+        Value::String(s) => {
+            write_uint(MAJOR_TEXT, s.len() as u64, out);
+            out.extend_from_slice(s.as_bytes());
+        }
+        // CBOR has no native arbitrary-precision decimal type that round-trips
+        // through this codec's plain tag-free text/float model, so these are
+        // encoded as text; decoding back yields `Value::String`, not the
+        // original variant.
+        #[cfg(feature = "bignum")]
+        Value::BigInt(b) => {
+            let s = b.to_string();
+            write_uint(MAJOR_TEXT, s.len() as u64, out);
+            out.extend_from_slice(s.as_bytes());
+        }
+        #[cfg(feature = "bignum")]
+        Value::Decimal(d) => {
+            let s = d.to_string();
+            write_uint(MAJOR_TEXT, s.len() as u64, out);
+            out.extend_from_slice(s.as_bytes());
+        }
+        Value::Array(items) => {
+            write_uint(MAJOR_ARRAY, items.len() as u64, out);
+            for item in items {
+                encode_into(item, out);
+            }
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            write_uint(MAJOR_MAP, keys.len() as u64, out);
+            for key in keys {
+                encode_into(&Value::String(key.clone()), out);
+                encode_into(&map[key], out);
+            }
+        }
+    }
+}
+
+fn write_uint(major: u8, n: u64, out: &mut Vec<u8>) {
+    let top = major << 5;
+    if n < 24 {
+        out.push(top | n as u8);
+    } else if n <= u8::MAX as u64 {
+        out.push(top | 24);
+        out.push(n as u8);
+    } else if n <= u16::MAX as u64 {
+        out.push(top | 25);
+        out.extend_from_slice(&(n as u16).to_be_bytes());
+    } else if n <= u32::MAX as u64 {
+        out.push(top | 26);
+        out.extend_from_slice(&(n as u32).to_be_bytes());
+    } else {
+        out.push(top | 27);
+        out.extend_from_slice(&n.to_be_bytes());
+    }
+}
+
+/// Decode a single CBOR-encoded `Value` from `data`, rejecting maps with
+/// non-text keys.
+pub fn decode(data: &[u8]) -> Result<Value> {
+    let (value, consumed) = decode_at(data, 0)?;
+    if consumed != data.len() {
+        return Err(AdfError::Other("trailing bytes after CBOR value".to_string()));
+    }
+    Ok(value)
+}
+
+fn decode_at(data: &[u8], pos: usize) -> Result<(Value, usize)> {
+    let head = *data
+        .get(pos)
+        .ok_or_else(|| AdfError::Other("unexpected end of CBOR input".to_string()))?;
+    let major = head >> 5;
+    let additional = head & 0x1f;
+
+    match major {
+        MAJOR_UNSIGNED => {
+            let (n, end) = read_uint(data, pos + 1, additional)?;
+            Ok((Value::Integer(n as i64), end))
+        }
+        MAJOR_NEGATIVE => {
+            let (n, end) = read_uint(data, pos + 1, additional)?;
+            Ok((Value::Integer(-1 - n as i64), end))
+        }
+        MAJOR_TEXT => {
+            let (len, start) = read_uint(data, pos + 1, additional)?;
+            let len = len as usize;
+            let bytes = data
+                .get(start..start + len)
+                .ok_or_else(|| AdfError::Other("truncated CBOR text string".to_string()))?;
+            let s = std::str::from_utf8(bytes)?.to_string();
+            Ok((Value::String(s), start + len))
+        }
+        MAJOR_ARRAY => {
+            let (len, mut cursor) = read_uint(data, pos + 1, additional)?;
+            let mut items = Vec::with_capacity(len as usize);
+            for _ in 0..len {
+                let (item, next) = decode_at(data, cursor)?;
+                items.push(item);
+                cursor = next;
+            }
+            Ok((Value::Array(items), cursor))
+        }
+        MAJOR_MAP => {
+            let (len, mut cursor) = read_uint(data, pos + 1, additional)?;
+            let mut map = HashMap::with_capacity(len as usize);
+            for _ in 0..len {
+                let (key, next) = decode_at(data, cursor)?;
+                let key = match key {
+                    Value::String(s) => s,
+                    other => {
+                        return Err(AdfError::Other(format!(
+                            "CBOR map key must be a string, found {:?}",
+                            other
+                        )))
+                    }
+                };
+                cursor = next;
+                let (value, next) = decode_at(data, cursor)?;
+                map.insert(key, value);
+                cursor = next;
+            }
+            Ok((Value::Object(map), cursor))
+        }
+        MAJOR_SIMPLE => match additional {
+            SIMPLE_FALSE => Ok((Value::Boolean(false), pos + 1)),
+            SIMPLE_TRUE => Ok((Value::Boolean(true), pos + 1)),
+            SIMPLE_NULL => Ok((Value::Null, pos + 1)),
+            SIMPLE_F64 => {
+                let bytes = data
+                    .get(pos + 1..pos + 9)
+                    .ok_or_else(|| AdfError::Other("truncated CBOR float".to_string()))?;
+                let f = f64::from_be_bytes(bytes.try_into().unwrap());
+                Ok((Value::Float(f), pos + 9))
+            }
+            other => Err(AdfError::Other(format!(
+                "unsupported CBOR simple value {}",
+                other
+            ))),
+        },
+        other => Err(AdfError::Other(format!(
+            "unsupported CBOR major type {}",
+            other
+        ))),
+    }
+}
+
+/// Read the length/value encoded by `additional` starting at `pos`, returning
+/// the decoded `u64` and the index just past the bytes it consumed.
+fn read_uint(data: &[u8], pos: usize, additional: u8) -> Result<(u64, usize)> {
+    match additional {
+        0..=23 => Ok((additional as u64, pos)),
+        24 => {
+            let b = *data
+                .get(pos)
+                .ok_or_else(|| AdfError::Other("truncated CBOR length".to_string()))?;
+            Ok((b as u64, pos + 1))
+        }
+        25 => {
+            let bytes = data
+                .get(pos..pos + 2)
+                .ok_or_else(|| AdfError::Other("truncated CBOR length".to_string()))?;
+            Ok((u16::from_be_bytes(bytes.try_into().unwrap()) as u64, pos + 2))
+        }
+        26 => {
+            let bytes = data
+                .get(pos..pos + 4)
+                .ok_or_else(|| AdfError::Other("truncated CBOR length".to_string()))?;
+            Ok((u32::from_be_bytes(bytes.try_into().unwrap()) as u64, pos + 4))
+        }
+        27 => {
+            let bytes = data
+                .get(pos..pos + 8)
+                .ok_or_else(|| AdfError::Other("truncated CBOR length".to_string()))?;
+            Ok((u64::from_be_bytes(bytes.try_into().unwrap()), pos + 8))
+        }
+        other => Err(AdfError::Other(format!(
+            "unsupported CBOR additional info {}",
+            other
+        ))),
+    }
+}
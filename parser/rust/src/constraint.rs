@@ -0,0 +1,263 @@
+use crate::error::{AdfError, Result, Span};
+use crate::value::Value;
+
+/// The scalar type named by a `(int)`, `(float)`, `(bool)`, or `(string)` constraint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypeConstraint {
+    Int,
+    Float,
+    Bool,
+    String,
+}
+
+impl TypeConstraint {
+    fn name(&self) -> &'static str {
+        match self {
+            TypeConstraint::Int => "int",
+            TypeConstraint::Float => "float",
+            TypeConstraint::Bool => "bool",
+            TypeConstraint::String => "string",
+        }
+    }
+
+    fn matches(&self, value: &Value) -> bool {
+        #[cfg(feature = "bignum")]
+        {
+            if matches!(
+                (self, value),
+                (TypeConstraint::Int, Value::BigInt(_)) | (TypeConstraint::Float, Value::Decimal(_))
+            ) {
+                return true;
+            }
+        }
+        matches!(
+            (self, value),
+            (TypeConstraint::Int, Value::Integer(_))
+                | (TypeConstraint::Float, Value::Float(_))
+                | (TypeConstraint::Bool, Value::Boolean(_))
+                | (TypeConstraint::String, Value::String(_))
+        )
+    }
+}
+
+/// A parsed `(...)` constraint captured by the lexer on a key-value or multiline value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    Type(TypeConstraint),
+    Range { min: i64, max: i64 },
+    Len { min: usize, max: usize },
+    Enum(Vec<String>),
+    Required,
+}
+
+impl Constraint {
+    /// Parse the text inside a constraint's parentheses, e.g. `"range 1..100"`.
+    ///
+    /// `line` is only used to attribute an `InvalidConstraint` error if the
+    /// constraint itself is malformed or unsatisfiable (e.g. `range 5..1`).
+    pub fn parse(raw: &str, line: usize) -> Result<Self> {
+        let raw = raw.trim();
+        let (head, rest) = match raw.split_once(char::is_whitespace) {
+            Some((h, r)) => (h, r.trim()),
+            None => (raw, ""),
+        };
+
+        match head {
+            "int" => Ok(Constraint::Type(TypeConstraint::Int)),
+            "float" => Ok(Constraint::Type(TypeConstraint::Float)),
+            "bool" => Ok(Constraint::Type(TypeConstraint::Bool)),
+            "string" => Ok(Constraint::Type(TypeConstraint::String)),
+            "required" => Ok(Constraint::Required),
+            "range" => Self::parse_range(rest, line),
+            "len" => Self::parse_len(rest, line),
+            "enum" => Self::parse_enum(rest, line),
+            other => Err(AdfError::invalid_constraint(
+                line,
+                format!("unknown constraint '{}'", other),
+            )),
+        }
+    }
+
+    fn parse_range(rest: &str, line: usize) -> Result<Self> {
+        let (min, max) = Self::parse_bounds(rest, line)?;
+        let min: i64 = min
+            .parse()
+            .map_err(|_| AdfError::invalid_constraint(line, format!("invalid range bound '{}'", min)))?;
+        let max: i64 = max
+            .parse()
+            .map_err(|_| AdfError::invalid_constraint(line, format!("invalid range bound '{}'", max)))?;
+        if min > max {
+            return Err(AdfError::invalid_constraint(
+                line,
+                format!("range {}..{} has min greater than max", min, max),
+            ));
+        }
+        Ok(Constraint::Range { min, max })
+    }
+
+    fn parse_len(rest: &str, line: usize) -> Result<Self> {
+        let (min, max) = Self::parse_bounds(rest, line)?;
+        let min: usize = min
+            .parse()
+            .map_err(|_| AdfError::invalid_constraint(line, format!("invalid len bound '{}'", min)))?;
+        let max: usize = max
+            .parse()
+            .map_err(|_| AdfError::invalid_constraint(line, format!("invalid len bound '{}'", max)))?;
+        if min > max {
+            return Err(AdfError::invalid_constraint(
+                line,
+                format!("len {}..{} has min greater than max", min, max),
+            ));
+        }
+        Ok(Constraint::Len { min, max })
+    }
+
+    fn parse_bounds(rest: &str, line: usize) -> Result<(&str, &str)> {
+        rest.split_once("..").ok_or_else(|| {
+            AdfError::invalid_constraint(line, format!("expected 'min..max', found '{}'", rest))
+        })
+    }
+
+    fn parse_enum(rest: &str, line: usize) -> Result<Self> {
+        let options: Vec<String> = rest.split('|').map(|s| s.trim().to_string()).collect();
+        if options.is_empty() || options.iter().any(|o| o.is_empty()) {
+            return Err(AdfError::invalid_constraint(
+                line,
+                format!("enum constraint has no options: '{}'", rest),
+            ));
+        }
+        Ok(Constraint::Enum(options))
+    }
+
+    /// Check `value` against this constraint, attributing any violation to `span`.
+    ///
+    /// `Required` only matters before a value exists (see [`Constraint::check_presence`]);
+    /// checking it against an already-parsed value is always satisfied.
+    pub fn check(&self, value: &Value, span: Span) -> Result<()> {
+        let line = span.line;
+        match self {
+            Constraint::Type(t) => {
+                if t.matches(value) {
+                    Ok(())
+                } else {
+                    Err(AdfError::PushingInvalidType {
+                        expected: t.name().to_string(),
+                        found: Self::type_name(value).to_string(),
+                        line,
+                        span: Some(span),
+                    })
+                }
+            }
+            Constraint::Range { min, max } => match value.as_i64() {
+                Some(i) if i >= *min && i <= *max => Ok(()),
+                Some(i) => Err(AdfError::RangeViolation {
+                    value: i.to_string(),
+                    min: *min,
+                    max: *max,
+                    line,
+                    span: Some(span),
+                }),
+                None => Err(AdfError::PushingInvalidType {
+                    expected: "int".to_string(),
+                    found: Self::type_name(value).to_string(),
+                    line,
+                    span: Some(span),
+                }),
+            },
+            Constraint::Len { min, max } => {
+                let len = match value {
+                    Value::Array(arr) => arr.len(),
+                    Value::String(s) => s.chars().count(),
+                    other => {
+                        return Err(AdfError::PushingInvalidType {
+                            expected: "string or array".to_string(),
+                            found: Self::type_name(other).to_string(),
+                            line,
+                            span: Some(span),
+                        })
+                    }
+                };
+                if len >= *min && len <= *max {
+                    Ok(())
+                } else if len > *max && matches!(value, Value::Array(_)) {
+                    Err(AdfError::IndexOutOfRange {
+                        index: len,
+                        size: *max,
+                        line,
+                    })
+                } else {
+                    Err(AdfError::RangeViolation {
+                        value: len.to_string(),
+                        min: *min as i64,
+                        max: *max as i64,
+                        line,
+                        span: Some(span),
+                    })
+                }
+            }
+            Constraint::Enum(options) => match value.to_string() {
+                Some(s) if options.contains(&s) => Ok(()),
+                Some(s) => Err(AdfError::EnumViolation {
+                    value: s,
+                    allowed: options.clone(),
+                    line,
+                    span: Some(span),
+                }),
+                None => Err(AdfError::PushingInvalidType {
+                    expected: "scalar".to_string(),
+                    found: Self::type_name(value).to_string(),
+                    line,
+                    span: Some(span),
+                }),
+            },
+            Constraint::Required => Ok(()),
+        }
+    }
+
+    /// Check a `(required)` constraint against the *absence* of a value.
+    pub fn check_presence(constraint: Option<&Constraint>, present: bool, span: Span, key: &str) -> Result<()> {
+        if matches!(constraint, Some(Constraint::Required)) && !present {
+            return Err(AdfError::validation_error_at(
+                span,
+                key,
+                format!("line {}: '{}' is required but missing", span.line, key),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate `value` against this constraint, applying an array's element
+    /// constraint to every element and a `Len` constraint to the array's size
+    /// rather than to each element individually.
+    pub fn check_value_or_elements(&self, value: &Value, span: Span) -> Result<()> {
+        if let Constraint::Len { .. } = self {
+            return self.check(value, span);
+        }
+
+        match value {
+            Value::Array(elements) => {
+                for element in elements {
+                    self.check(element, span)?;
+                }
+                Ok(())
+            }
+            other => self.check(other, span),
+        }
+    }
+
+    fn type_name(value: &Value) -> &'static str {
+        match value {
+            Value::String(_) => "string",
+            Value::Integer(_) => "int",
+            Value::Float(_) => "float",
+            #[cfg(feature = "bignum")]
+            Value::BigInt(_) => "bigint",
+            #[cfg(feature = "bignum")]
+            Value::Decimal(_) => "decimal",
+            Value::Boolean(_) => "bool",
+            Value::Null => "null",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+}
@@ -2,11 +2,24 @@ use crate::error::{AdfError, Result};
 use crate::value::Value;
 use std::collections::HashMap;
 
+/// Options controlling how [`Document::to_adf_with`] renders ADF source text.
+#[derive(Debug, Clone, Default)]
+pub struct SerializeOptions {
+    /// Spaces to indent each `key = value` line by.
+    pub indent: usize,
+    /// Emit nested sections as relative headers (`path:`) instead of
+    /// absolute headers (`# path:`). Note this changes where the data lands
+    /// on re-parse (relative sections, not the document root), so it is not
+    /// round-trip-safe; it exists for producing more readable nested output.
+    pub prefer_relative_headers: bool,
+}
+
 /// Represents a parsed ADF document
 #[derive(Debug, Clone, PartialEq)]
 pub struct Document {
     root: HashMap<String, Value>,
     relative_sections: HashMap<String, Value>,
+    imports: Vec<String>,
 }
 
 impl Document {
@@ -15,9 +28,19 @@ impl Document {
         Document {
             root: HashMap::new(),
             relative_sections: HashMap::new(),
+            imports: Vec::new(),
         }
     }
 
+    /// `@import "..."` directive paths collected while parsing, in source order.
+    pub(crate) fn imports(&self) -> &[String] {
+        &self.imports
+    }
+
+    pub(crate) fn set_imports(&mut self, imports: Vec<String>) {
+        self.imports = imports;
+    }
+
     /// Get a value by dot-notation path (returns clone)
     pub fn get(&self, path: &str) -> Option<Value> {
         if path.is_empty() {
@@ -127,12 +150,203 @@ impl Document {
         &self.root
     }
 
-    /// Convert to JSON string (requires serde feature)
-    #[cfg(feature = "serde")]
+    /// Convert to JSON string (requires the `serde` feature; unavailable
+    /// together with `bignum` since `Value` doesn't derive serde support
+    /// in that combination — see the comment on `Value`'s derive).
+    #[cfg(all(feature = "serde", not(feature = "bignum")))]
     pub fn to_json(&self) -> Result<String> {
         serde_json::to_string_pretty(&self.root).map_err(|e| AdfError::Other(e.to_string()))
     }
 
+    /// Encode this document as canonical CBOR (object keys sorted), giving a
+    /// deterministic, hashable binary representation.
+    #[cfg(feature = "cbor")]
+    pub fn to_cbor(&self) -> Result<Vec<u8>> {
+        Ok(crate::cbor::encode(&Value::Object(self.root.clone())))
+    }
+
+    /// Decode a document previously produced by [`Document::to_cbor`].
+    ///
+    /// Fails if the top-level CBOR value isn't a map, or if any map
+    /// (including nested ones) has a non-string key.
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor(data: &[u8]) -> Result<Document> {
+        match crate::cbor::decode(data)? {
+            Value::Object(root) => Ok(Document {
+                root,
+                relative_sections: HashMap::new(),
+                imports: Vec::new(),
+            }),
+            other => Err(AdfError::Other(format!(
+                "expected a CBOR map at the document root, found {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Run a path-selector query against this document, returning a clone of
+    /// every node the selector matches. Supports dot-separated field names,
+    /// `*` (every key/index at a level), `**` (recursive descent), `[n]`
+    /// (array indexing), and bracketed predicates like `[age>10]` or
+    /// `[name="Alice"]` that filter an array's elements by comparing a
+    /// child field (`as_f64`/`as_str` are used to coerce the comparison).
+    pub fn select(&self, selector: &str) -> Result<Vec<Value>> {
+        crate::select::select(self, selector)
+    }
+
+    /// Render this document back into ADF source text using default options.
+    ///
+    /// For a document produced by [`crate::parse`], `parse(doc.to_adf())`
+    /// gives back an equal `Document`, with these exceptions: only the root
+    /// data is written, so relative sections and `@import` directives are
+    /// not emitted; and string values are written unquoted, so a string with
+    /// leading or trailing spaces, or ending in a `(...)` that re-parses as a
+    /// constraint, comes back changed.
+    pub fn to_adf(&self) -> String {
+        self.to_adf_with(&SerializeOptions::default())
+    }
+
+    /// Render this document back into ADF source text with custom `options`.
+    pub fn to_adf_with(&self, options: &SerializeOptions) -> String {
+        let mut out = String::new();
+        Self::emit_object(&self.root, "", options, &mut out);
+        out
+    }
+
+    fn emit_object(map: &HashMap<String, Value>, path: &str, options: &SerializeOptions, out: &mut String) {
+        let mut keys: Vec<&String> = map.keys().collect();
+        keys.sort();
+
+        let scalars: Vec<&String> = keys
+            .iter()
+            .filter(|k| !matches!(map[**k], Value::Object(_) | Value::Array(_)))
+            .copied()
+            .collect();
+
+        if !scalars.is_empty() {
+            out.push_str(&Self::header_line(path, options));
+            for key in &scalars {
+                out.push_str(&Self::kv_line(key, &map[*key], options));
+            }
+            out.push('\n');
+        }
+
+        for key in keys {
+            let value = &map[key];
+            let child_path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            match value {
+                Value::Object(obj) => Self::emit_object(obj, &child_path, options, out),
+                Value::Array(items) => Self::emit_array(items, &child_path, options, out),
+                _ => {} // already emitted above as a scalar key-value
+            }
+        }
+    }
+
+    fn emit_array(items: &[Value], path: &str, options: &SerializeOptions, out: &mut String) {
+        out.push_str(&Self::header_line(path, options));
+
+        if !items.is_empty() && items.iter().all(|v| v.is_object()) {
+            for item in items {
+                if let Value::Object(obj) = item {
+                    let mut keys: Vec<&String> = obj.keys().collect();
+                    keys.sort();
+                    for key in keys {
+                        out.push_str(&Self::kv_line(key, &obj[key], options));
+                    }
+                }
+                out.push('\n');
+            }
+        } else {
+            for item in items {
+                out.push_str(&Self::scalar_repr(item));
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+    }
+
+    fn header_line(path: &str, options: &SerializeOptions) -> String {
+        if path.is_empty() {
+            return "#:\n".to_string();
+        }
+        if options.prefer_relative_headers {
+            format!("{}:\n", path)
+        } else {
+            format!("# {}:\n", path)
+        }
+    }
+
+    fn kv_line(key: &str, value: &Value, options: &SerializeOptions) -> String {
+        let indent = " ".repeat(options.indent);
+        match value {
+            Value::String(s) if s.contains('\n') => {
+                format!("{}{} = \"\"\"\n{}\n\"\"\"\n", indent, key, s)
+            }
+            other => format!("{}{} = {}\n", indent, key, Self::scalar_repr(other)),
+        }
+    }
+
+    fn scalar_repr(value: &Value) -> String {
+        match value {
+            Value::String(s) => s.clone(),
+            Value::Integer(i) => i.to_string(),
+            Value::Float(f) => Self::render_float(*f),
+            #[cfg(feature = "bignum")]
+            Value::BigInt(b) => b.to_string(),
+            #[cfg(feature = "bignum")]
+            Value::Decimal(d) => d.to_string(),
+            Value::Boolean(b) => b.to_string(),
+            Value::Null => String::new(),
+            Value::Array(_) | Value::Object(_) => String::new(),
+        }
+    }
+
+    /// Render a float so it re-infers as a `Float` on re-parse.
+    ///
+    /// Under the `bignum` feature, any plain `digits.digits` literal infers as
+    /// a `Decimal` instead (see `Parser::looks_like_decimal`) — only
+    /// scientific notation reaches the `f64` fallback that yields a `Float`.
+    /// So with `bignum`, always render in scientific notation; without it,
+    /// just force a fractional part (e.g. `2500.0`) so an integral float
+    /// doesn't re-infer as an `Integer`.
+    #[cfg(feature = "bignum")]
+    fn render_float(f: f64) -> String {
+        if f.is_finite() {
+            format!("{:e}", f)
+        } else {
+            f.to_string()
+        }
+    }
+
+    #[cfg(not(feature = "bignum"))]
+    fn render_float(f: f64) -> String {
+        let rendered = f.to_string();
+        if !f.is_finite() || rendered.contains(['.', 'e', 'E']) {
+            rendered
+        } else {
+            format!("{}.0", rendered)
+        }
+    }
+
+    /// Append `key` to a dot-notation `path`, quoting it (the same way
+    /// [`Document::parse_path`] expects) if it contains a literal dot.
+    pub(crate) fn append_path(path: &str, key: &str) -> String {
+        let quoted = if key.contains('.') {
+            format!("\"{}\"", key)
+        } else {
+            key.to_string()
+        };
+        if path.is_empty() {
+            quoted
+        } else {
+            format!("{}.{}", path, quoted)
+        }
+    }
+
     /// Parse a dot-notation path into parts
     fn parse_path(path: &str) -> Vec<String> {
         let mut parts = Vec::new();
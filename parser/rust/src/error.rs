@@ -1,4 +1,42 @@
 use thiserror::Error;
+use std::fmt;
+
+/// A source location spanning from `col_start` to `col_end` (0-based, exclusive)
+/// on `line` (1-based), used to render caret-underlined diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub col_start: usize,
+    pub col_end: usize,
+}
+
+impl Span {
+    pub fn new(line: usize, col_start: usize, col_end: usize) -> Self {
+        Span {
+            line,
+            col_start,
+            col_end,
+        }
+    }
+
+    /// Render a caret-underlined snippet of `source`'s line for this span, e.g.:
+    /// ```text
+    ///   name == Matthew
+    ///        ^^
+    /// ```
+    pub fn render(&self, source: &str) -> Option<String> {
+        let line_text = source.lines().nth(self.line.checked_sub(1)?)?;
+        let width = self.col_end.saturating_sub(self.col_start).max(1);
+        let caret = " ".repeat(self.col_start) + &"^".repeat(width);
+        Some(format!("  {}\n  {}", line_text, caret))
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.col_start + 1)
+    }
+}
 
 #[derive(Error, Debug)]
 pub enum AdfError {
@@ -7,10 +45,15 @@ pub enum AdfError {
         line: usize,
         message: String,
         context: Option<String>,
+        span: Option<Span>,
     },
 
     #[error("Validation error at path '{path}': {message}")]
-    ValidationError { path: String, message: String },
+    ValidationError {
+        path: String,
+        message: String,
+        span: Option<Span>,
+    },
 
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
@@ -18,6 +61,41 @@ pub enum AdfError {
     #[error("UTF-8 error: {0}")]
     Utf8Error(#[from] std::str::Utf8Error),
 
+    #[error("line {line}: invalid constraint: {message}")]
+    InvalidConstraint { line: usize, message: String },
+
+    #[error("line {line}: expected {expected}, found {found}")]
+    PushingInvalidType {
+        expected: String,
+        found: String,
+        line: usize,
+        span: Option<Span>,
+    },
+
+    #[error("line {line}: index {index} exceeds declared size {size}")]
+    IndexOutOfRange {
+        index: usize,
+        size: usize,
+        line: usize,
+    },
+
+    #[error("line {line}: value {value} is outside the range {min}..{max}")]
+    RangeViolation {
+        value: String,
+        min: i64,
+        max: i64,
+        line: usize,
+        span: Option<Span>,
+    },
+
+    #[error("line {line}: value '{value}' is not one of {allowed:?}")]
+    EnumViolation {
+        value: String,
+        allowed: Vec<String>,
+        line: usize,
+        span: Option<Span>,
+    },
+
     #[error("{0}")]
     Other(String),
 }
@@ -30,6 +108,7 @@ impl AdfError {
             line,
             message: message.into(),
             context: None,
+            span: None,
         }
     }
 
@@ -42,6 +121,38 @@ impl AdfError {
             line,
             message: message.into(),
             context: Some(context.into()),
+            span: None,
+        }
+    }
+
+    /// A parse error that can be rendered with a caret-underlined snippet.
+    pub fn parse_error_at(span: Span, message: impl Into<String>) -> Self {
+        AdfError::ParseError {
+            line: span.line,
+            message: message.into(),
+            context: None,
+            span: Some(span),
+        }
+    }
+
+    /// The [`Span`] attached to this error, if it carries one.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            AdfError::ParseError { span, .. }
+            | AdfError::ValidationError { span, .. }
+            | AdfError::PushingInvalidType { span, .. }
+            | AdfError::RangeViolation { span, .. }
+            | AdfError::EnumViolation { span, .. } => *span,
+            _ => None,
+        }
+    }
+
+    /// Render this error's message, followed by a caret-underlined snippet of
+    /// `source` if the error carries a [`Span`].
+    pub fn render(&self, source: &str) -> String {
+        match self.span().and_then(|span| span.render(source)) {
+            Some(snippet) => format!("{}\n{}", self, snippet),
+            None => self.to_string(),
         }
     }
 
@@ -49,6 +160,23 @@ impl AdfError {
         AdfError::ValidationError {
             path: path.into(),
             message: message.into(),
+            span: None,
+        }
+    }
+
+    /// A validation error that can be rendered with a caret-underlined snippet.
+    pub fn validation_error_at(span: Span, path: impl Into<String>, message: impl Into<String>) -> Self {
+        AdfError::ValidationError {
+            path: path.into(),
+            message: message.into(),
+            span: Some(span),
+        }
+    }
+
+    pub fn invalid_constraint(line: usize, message: impl Into<String>) -> Self {
+        AdfError::InvalidConstraint {
+            line,
+            message: message.into(),
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::error::Result;
+use crate::error::{AdfError, Result, Span};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenType {
@@ -10,6 +10,7 @@ pub enum TokenType {
     MultilineStart,
     MultilineContent,
     MultilineEnd,
+    Import,
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +24,11 @@ pub struct Token {
     pub value: Option<String>,
     pub constraint: Option<String>,
     pub quote_count: Option<usize>,
+    /// Column (0-based) where the token's significant content begins, e.g.
+    /// the start of a header's path or a key-value's key.
+    pub column_start: Option<usize>,
+    /// Column (0-based, exclusive) where the token's significant content ends.
+    pub column_end: Option<usize>,
 }
 
 impl Token {
@@ -37,13 +43,26 @@ impl Token {
             value: None,
             constraint: None,
             quote_count: None,
+            column_start: None,
+            column_end: None,
         }
     }
+
+    /// The token's location as a [`Span`], falling back to the whole raw line
+    /// when no column information was recorded for it.
+    pub fn span(&self) -> Span {
+        Span::new(
+            self.line_number,
+            self.column_start.unwrap_or(0),
+            self.column_end.unwrap_or(self.raw_line.len()),
+        )
+    }
 }
 
 pub struct Lexer {
     in_multiline: bool,
     multiline_quote_count: usize,
+    multiline_start_line: usize,
 }
 
 impl Lexer {
@@ -51,6 +70,7 @@ impl Lexer {
         Lexer {
             in_multiline: false,
             multiline_quote_count: 0,
+            multiline_start_line: 0,
         }
     }
 
@@ -64,6 +84,13 @@ impl Lexer {
             }
         }
 
+        if self.in_multiline {
+            return Err(AdfError::parse_error_at(
+                Span::new(self.multiline_start_line, 0, 0),
+                "unterminated multiline value: missing closing quotes",
+            ));
+        }
+
         Ok(tokens)
     }
 
@@ -85,6 +112,17 @@ impl Lexer {
             )));
         }
 
+        // Import directive, e.g. `@import "other.adf"`
+        if let Some(path) = Self::try_parse_import(line) {
+            return Ok(Some(Token {
+                token_type: TokenType::Import,
+                line_number,
+                raw_line: line.to_string(),
+                value: Some(path),
+                ..Token::new(TokenType::Import, line_number, line.to_string())
+            }));
+        }
+
         // Try to parse header
         if let Some(token) = self.try_parse_header(line, line_number)? {
             return Ok(Some(token));
@@ -96,11 +134,13 @@ impl Lexer {
         }
 
         // Scalar value
+        let (value, constraint) = Self::parse_value_and_constraint(line.trim());
         Ok(Some(Token {
             token_type: TokenType::ScalarValue,
             line_number,
             raw_line: line.to_string(),
-            value: Some(line.trim().to_string()),
+            value: Some(value),
+            constraint,
             ..Token::new(TokenType::ScalarValue, line_number, line.to_string())
         }))
     }
@@ -115,9 +155,14 @@ impl Lexer {
         let mut path_part = stripped[..stripped.len() - 1].trim().to_string();
         let is_absolute = path_part.starts_with('#');
 
+        let mut col_start = line.len() - line.trim_start().len();
         if is_absolute {
             path_part = path_part[1..].trim().to_string();
+            col_start += 1;
+            let after_hash = &line[col_start..];
+            col_start += after_hash.len() - after_hash.trim_start().len();
         }
+        let col_end = col_start + path_part.len();
 
         // Root section
         if path_part.is_empty() && is_absolute {
@@ -127,6 +172,8 @@ impl Lexer {
                 raw_line: line.to_string(),
                 path: Some(String::new()),
                 is_absolute: Some(true),
+                column_start: Some(col_start),
+                column_end: Some(col_end),
                 ..Token::new(TokenType::AbsoluteHeader, line_number, line.to_string())
             }));
         }
@@ -136,6 +183,15 @@ impl Lexer {
         }
 
         if !Self::is_valid_path(&path_part) {
+            if is_absolute {
+                // An explicit `#` marks this as an intended header, so a bad
+                // path is a real error rather than silently falling through
+                // to key-value/scalar handling.
+                return Err(AdfError::parse_error_at(
+                    Span::new(line_number, col_start, col_end),
+                    format!("malformed header path '{}'", path_part),
+                ));
+            }
             return Ok(None);
         }
 
@@ -149,6 +205,8 @@ impl Lexer {
             raw_line: line.to_string(),
             path: Some(path_part),
             is_absolute: Some(is_absolute),
+            column_start: Some(col_start),
+            column_end: Some(col_end),
             ..Token::new(
                 if is_absolute {
                     TokenType::AbsoluteHeader
@@ -167,12 +225,15 @@ impl Lexer {
         let raw_value = &line[equals_pos + 1..].trim_start();
 
         let key = raw_key.to_string();
+        let key_col_start = line.len() - line.trim_start().len();
+        let key_col_end = key_col_start + raw_key.len();
 
         // Check for multiline value
         let quote_count = Self::count_leading_quotes(raw_value);
         if quote_count > 0 {
             self.in_multiline = true;
             self.multiline_quote_count = quote_count;
+            self.multiline_start_line = line_number;
 
             // Check if it ends on same line
             if raw_value.len() > quote_count * 2
@@ -190,6 +251,8 @@ impl Lexer {
                     key: Some(key),
                     value: Some(value),
                     constraint,
+                    column_start: Some(key_col_start),
+                    column_end: Some(key_col_end),
                     ..Token::new(TokenType::KeyValue, line_number, line.to_string())
                 });
             } else {
@@ -207,6 +270,8 @@ impl Lexer {
                     key: Some(key),
                     value: Some(content),
                     quote_count: Some(quote_count),
+                    column_start: Some(key_col_start),
+                    column_end: Some(key_col_end),
                     ..Token::new(TokenType::MultilineStart, line_number, line.to_string())
                 });
             }
@@ -222,6 +287,8 @@ impl Lexer {
             key: Some(key),
             value: Some(value),
             constraint,
+            column_start: Some(key_col_start),
+            column_end: Some(key_col_end),
             ..Token::new(TokenType::KeyValue, line_number, line.to_string())
         })
     }
@@ -257,6 +324,16 @@ impl Lexer {
         }
     }
 
+    /// Parse an `@import "path.adf"` directive, returning the quoted path.
+    fn try_parse_import(line: &str) -> Option<String> {
+        let rest = line.trim().strip_prefix("@import")?.trim();
+        if rest.len() >= 2 && rest.starts_with('"') && rest.ends_with('"') {
+            Some(rest[1..rest.len() - 1].to_string())
+        } else {
+            None
+        }
+    }
+
     fn is_valid_path(path: &str) -> bool {
         if path.is_empty() {
             return true;
@@ -312,7 +389,7 @@ impl Lexer {
 
     fn parse_value_and_constraint(s: &str) -> (String, Option<String>) {
         let constraint = Self::parse_constraint(s);
-        if let Some(_) = &constraint {
+        if constraint.is_some() {
             if let Some(paren_pos) = s.rfind('(') {
                 let value = s[..paren_pos].trim_end().to_string();
                 return (value, constraint);
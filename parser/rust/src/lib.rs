@@ -20,13 +20,22 @@ println!("Name: {}", name_value.as_str().unwrap());
 ```
 */
 
+#[cfg(feature = "cbor")]
+mod cbor;
+mod constraint;
 mod document;
 mod error;
 mod lexer;
 mod parser;
+mod resolve;
+mod schema;
+mod select;
 mod value;
 
-pub use document::Document;
+pub use constraint::{Constraint, TypeConstraint};
+pub use document::{Document, SerializeOptions};
 pub use error::{AdfError, Result};
 pub use parser::{parse, parse_file, parse_with_options, ParseOptions};
+pub use resolve::resolve;
+pub use schema::{Definition, FieldSpec, ScalarType, Schema};
 pub use value::Value;
@@ -1,3 +1,4 @@
+use crate::constraint::Constraint;
 use crate::document::Document;
 use crate::error::{AdfError, Result};
 use crate::lexer::{Lexer, Token, TokenType};
@@ -7,12 +8,16 @@ use std::collections::HashMap;
 pub struct Parser {
     infer_types: bool,
     strict: bool,
+    validate_constraints: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct ParseOptions {
     pub infer_types: bool,
     pub strict: bool,
+    /// When `true`, each parsed `Value` is checked against the `(...)` constraint
+    /// captured on its token, if any, and a violation fails the parse.
+    pub validate_constraints: bool,
 }
 
 impl Default for ParseOptions {
@@ -20,6 +25,7 @@ impl Default for ParseOptions {
         ParseOptions {
             infer_types: true,
             strict: false,
+            validate_constraints: false,
         }
     }
 }
@@ -29,15 +35,51 @@ impl Parser {
         Parser {
             infer_types: options.infer_types,
             strict: options.strict,
+            validate_constraints: options.validate_constraints,
         }
     }
 
+    /// Parse and check a token's raw constraint text, then validate `value`
+    /// against it. A no-op when `validate_constraints` is disabled or the
+    /// token carries no constraint.
+    fn check_constraint(&self, token: &Token, value: &Value) -> Result<()> {
+        if !self.validate_constraints {
+            return Ok(());
+        }
+        if let Some(raw) = &token.constraint {
+            let constraint = Constraint::parse(raw, token.line_number)?;
+            if let Some(key) = &token.key {
+                let present = !Self::is_empty_value(value);
+                Constraint::check_presence(Some(&constraint), present, token.span(), key)?;
+            }
+            constraint.check_value_or_elements(value, token.span())?;
+        }
+        Ok(())
+    }
+
+    /// Whether a value counts as "absent" for a `(required)` constraint:
+    /// an empty string (e.g. `name =  (required)`) or null.
+    fn is_empty_value(value: &Value) -> bool {
+        matches!(value, Value::String(s) if s.is_empty()) || matches!(value, Value::Null)
+    }
+
     pub fn parse(&self, text: &str) -> Result<Document> {
         let mut lexer = Lexer::new();
         let tokens = lexer.tokenize(text)?;
 
+        let imports: Vec<String> = tokens
+            .iter()
+            .filter(|t| t.token_type == TokenType::Import)
+            .filter_map(|t| t.value.clone())
+            .collect();
+        let tokens: Vec<Token> = tokens
+            .into_iter()
+            .filter(|t| t.token_type != TokenType::Import)
+            .collect();
+
         let mut document = Document::new();
         self.parse_tokens(&tokens, &mut document)?;
+        document.set_imports(imports);
 
         Ok(document)
     }
@@ -126,6 +168,13 @@ impl Parser {
                 .map(|v| self.infer_type(v))
                 .collect();
 
+            if self.validate_constraints {
+                let array_value = Value::Array(values.clone());
+                for &token in &content_tokens {
+                    self.check_constraint(token, &array_value)?;
+                }
+            }
+
             if is_absolute {
                 document.set(section_path, Value::Array(values))?;
             } else {
@@ -203,13 +252,19 @@ impl Parser {
                 }
                 TokenType::KeyValue => {
                     if let (Some(key), Some(value)) = (&token.key, &token.value) {
-                        current_object.insert(key.clone(), self.infer_type(value));
+                        let value = self.infer_type(value);
+                        self.check_constraint(token, &value)?;
+                        current_object.insert(key.clone(), value);
                     }
                 }
                 TokenType::MultilineStart => {
-                    let (value, new_i) = self.collect_multiline(tokens, i)?;
+                    let (value, new_i, constraint_token) = self.collect_multiline(tokens, i)?;
+                    let value = Value::String(value);
+                    if let Some(t) = &constraint_token {
+                        self.check_constraint(t, &value)?;
+                    }
                     if let Some(key) = &token.key {
-                        current_object.insert(key.clone(), Value::String(value));
+                        current_object.insert(key.clone(), value);
                     }
                     i = new_i;
                 }
@@ -237,13 +292,19 @@ impl Parser {
             match token.token_type {
                 TokenType::KeyValue => {
                     if let (Some(key), Some(value)) = (&token.key, &token.value) {
-                        self.set_nested_value(&mut obj, key, self.infer_type(value))?;
+                        let value = self.infer_type(value);
+                        self.check_constraint(token, &value)?;
+                        self.set_nested_value(&mut obj, key, value)?;
                     }
                 }
                 TokenType::MultilineStart => {
-                    let (value, new_i) = self.collect_multiline(tokens, i)?;
+                    let (value, new_i, constraint_token) = self.collect_multiline(tokens, i)?;
+                    let value = Value::String(value);
+                    if let Some(t) = &constraint_token {
+                        self.check_constraint(t, &value)?;
+                    }
                     if let Some(key) = &token.key {
-                        self.set_nested_value(&mut obj, key, Value::String(value))?;
+                        self.set_nested_value(&mut obj, key, value)?;
                     }
                     i = new_i;
                 }
@@ -256,7 +317,14 @@ impl Parser {
         Ok(obj)
     }
 
-    fn collect_multiline(&self, tokens: &[Token], start_idx: usize) -> Result<(String, usize)> {
+    /// Collect a multiline value's content starting at `start_idx`, returning
+    /// the joined text, the index of its terminating `MultilineEnd` token, and
+    /// that token (which may carry the value's constraint).
+    fn collect_multiline<'a>(
+        &self,
+        tokens: &'a [Token],
+        start_idx: usize,
+    ) -> Result<(String, usize, Option<&'a Token>)> {
         let mut parts = Vec::new();
 
         // Add initial content
@@ -267,6 +335,7 @@ impl Parser {
         }
 
         let mut i = start_idx + 1;
+        let mut end_token = None;
         while i < tokens.len() {
             let token = &tokens[i];
 
@@ -282,6 +351,7 @@ impl Parser {
                             parts.push(value.clone());
                         }
                     }
+                    end_token = Some(token);
                     break;
                 }
                 _ => {}
@@ -290,7 +360,7 @@ impl Parser {
             i += 1;
         }
 
-        Ok((parts.join("\n"), i))
+        Ok((parts.join("\n"), i, end_token))
     }
 
     fn set_nested_value(
@@ -344,6 +414,25 @@ impl Parser {
             return Value::Integer(i);
         }
 
+        #[cfg(feature = "bignum")]
+        {
+            // A digit string that overflowed i64 above: keep it exact rather
+            // than falling through to a lossy f64.
+            if Self::looks_like_integer(value) {
+                if let Ok(b) = value.parse::<num_bigint::BigInt>() {
+                    return Value::BigInt(b);
+                }
+            }
+
+            // A decimal (e.g. a monetary amount): preserve exactly rather
+            // than rounding it through f64.
+            if Self::looks_like_decimal(value) {
+                if let Ok(d) = value.parse::<bigdecimal::BigDecimal>() {
+                    return Value::Decimal(d);
+                }
+            }
+        }
+
         // Try float
         if let Ok(f) = value.parse::<f64>() {
             return Value::Float(f);
@@ -352,6 +441,29 @@ impl Parser {
         // Keep as string
         Value::String(value.to_string())
     }
+
+    /// A plain (optionally negative) digit string, e.g. `"12345678901234567890"`.
+    #[cfg(feature = "bignum")]
+    fn looks_like_integer(value: &str) -> bool {
+        let digits = value.strip_prefix('-').unwrap_or(value);
+        !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+    }
+
+    /// A plain (optionally negative) `digits.digits` string, e.g. `"19.99"`.
+    /// Excludes scientific notation, which is left to the `f64` fallback.
+    #[cfg(feature = "bignum")]
+    fn looks_like_decimal(value: &str) -> bool {
+        let rest = value.strip_prefix('-').unwrap_or(value);
+        match rest.split_once('.') {
+            Some((int_part, frac_part)) => {
+                !int_part.is_empty()
+                    && !frac_part.is_empty()
+                    && int_part.chars().all(|c| c.is_ascii_digit())
+                    && frac_part.chars().all(|c| c.is_ascii_digit())
+            }
+            None => false,
+        }
+    }
 }
 
 // Helper trait for getting mutable object from Value
@@ -0,0 +1,71 @@
+//! Resolve `@import "path.adf"` directives, pulling in and merging other ADF
+//! files so a large config can be split into composable fragments.
+
+use crate::document::Document;
+use crate::error::{AdfError, Result};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Parse `document` is assumed to already be done; this walks its
+/// `@import` directives (and, recursively, those of every file it imports),
+/// merging each imported document in at the root alongside `document`'s own
+/// data. Relative import paths resolve against `base_dir`.
+///
+/// Fails with `AdfError::Other` if an import chain revisits a file already
+/// on the current resolution stack.
+pub fn resolve(document: Document, base_dir: &Path) -> Result<Document> {
+    let mut stack = Vec::new();
+    resolve_with_stack(document, base_dir, &mut stack)
+}
+
+fn resolve_with_stack(document: Document, base_dir: &Path, stack: &mut Vec<PathBuf>) -> Result<Document> {
+    // Imports are merged in first, then the importing document itself is
+    // merged on top, so its own values always win over whatever it imports
+    // (an importing file overriding an imported default, not the reverse).
+    let mut result = Document::new();
+
+    for import_path in document.imports() {
+        let resolved_path = base_dir.join(import_path);
+        let canonical = resolved_path.canonicalize()?;
+
+        if stack.contains(&canonical) {
+            return Err(AdfError::Other(format!(
+                "import cycle: {}",
+                cycle_description(stack, &canonical)
+            )));
+        }
+
+        let text = std::fs::read_to_string(&resolved_path)?;
+        let imported = crate::parse(&text)?;
+        let imported_base_dir = resolved_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        stack.push(canonical);
+        let resolved_import = resolve_with_stack(imported, &imported_base_dir, stack)?;
+        stack.pop();
+
+        result.merge(&resolved_import);
+    }
+
+    result.merge(&document);
+    Ok(result)
+}
+
+fn cycle_description(stack: &[PathBuf], repeated: &Path) -> String {
+    let mut seen = HashSet::new();
+    let names: Vec<String> = stack
+        .iter()
+        .chain(std::iter::once(&repeated.to_path_buf()))
+        .map(|p| file_label(p, &mut seen))
+        .collect();
+    names.join(" -> ")
+}
+
+fn file_label(path: &Path, seen: &mut HashSet<PathBuf>) -> String {
+    seen.insert(path.to_path_buf());
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
@@ -0,0 +1,219 @@
+//! Optional schema definition and validation for [`crate::Document`], so a
+//! document's expected shape can be declared and enforced rather than left
+//! as a loosely-typed bag of values.
+
+use crate::document::Document;
+use crate::error::{AdfError, Result};
+use crate::value::Value;
+use std::collections::HashMap;
+
+/// The scalar types a [`Definition::Scalar`] may require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarType {
+    String,
+    Integer,
+    Float,
+    Boolean,
+}
+
+impl ScalarType {
+    fn matches(&self, value: &Value) -> bool {
+        matches!(
+            (self, value),
+            (ScalarType::String, Value::String(_))
+                | (ScalarType::Integer, Value::Integer(_))
+                | (ScalarType::Float, Value::Float(_))
+                | (ScalarType::Boolean, Value::Boolean(_))
+        )
+    }
+}
+
+/// One field of a [`Definition::Object`]: the shape it must have, and
+/// whether it may be omitted.
+#[derive(Debug, Clone)]
+pub struct FieldSpec {
+    pub definition: Definition,
+    pub required: bool,
+}
+
+impl FieldSpec {
+    pub fn required(definition: Definition) -> Self {
+        FieldSpec {
+            definition,
+            required: true,
+        }
+    }
+
+    pub fn optional(definition: Definition) -> Self {
+        FieldSpec {
+            definition,
+            required: false,
+        }
+    }
+}
+
+/// The expected shape of a `Value` (or sub-tree of one).
+#[derive(Debug, Clone)]
+pub enum Definition {
+    Scalar(ScalarType),
+    Object(HashMap<String, FieldSpec>),
+    ArrayOf(Box<Definition>),
+    Union(Vec<Definition>),
+    /// A reference to a definition registered with [`Schema::define`].
+    Ref(String),
+}
+
+/// A set of named definitions plus a root shape, validated against a
+/// [`Document`] with [`Schema::validate`]/[`Schema::validate_strict`].
+#[derive(Debug, Clone)]
+pub struct Schema {
+    root: Definition,
+    definitions: HashMap<String, Definition>,
+}
+
+impl Schema {
+    /// Create a schema whose document root must match `root`.
+    pub fn new(root: Definition) -> Self {
+        Schema {
+            root,
+            definitions: HashMap::new(),
+        }
+    }
+
+    /// Register a named definition that [`Definition::Ref`] can point to.
+    pub fn define(mut self, name: impl Into<String>, definition: Definition) -> Self {
+        self.definitions.insert(name.into(), definition);
+        self
+    }
+
+    /// Validate `document`, ignoring object keys not named by the schema.
+    pub fn validate(&self, document: &Document) -> Result<()> {
+        self.validate_with(document, false)
+    }
+
+    /// Validate `document`, rejecting any object key not named by the schema.
+    pub fn validate_strict(&self, document: &Document) -> Result<()> {
+        self.validate_with(document, true)
+    }
+
+    fn validate_with(&self, document: &Document, strict: bool) -> Result<()> {
+        let root_value = Value::Object(document.as_map().clone());
+        self.check(&self.root, &root_value, "", strict)
+    }
+
+    fn resolve<'a>(&'a self, definition: &'a Definition) -> Result<&'a Definition> {
+        match definition {
+            Definition::Ref(name) => {
+                let target = self.definitions.get(name).ok_or_else(|| {
+                    AdfError::Other(format!("schema has no definition named '{}'", name))
+                })?;
+                self.resolve(target)
+            }
+            other => Ok(other),
+        }
+    }
+
+    fn check(&self, definition: &Definition, value: &Value, path: &str, strict: bool) -> Result<()> {
+        match self.resolve(definition)? {
+            Definition::Scalar(scalar) => {
+                if scalar.matches(value) {
+                    Ok(())
+                } else {
+                    Err(AdfError::validation_error(
+                        path,
+                        format!("expected {:?}, found {}", scalar, Self::type_name(value)),
+                    ))
+                }
+            }
+            Definition::Object(fields) => self.check_object(fields, value, path, strict),
+            Definition::ArrayOf(element) => self.check_array(element, value, path, strict),
+            Definition::Union(alternatives) => self.check_union(alternatives, value, path, strict),
+            Definition::Ref(_) => unreachable!("resolve() never returns a Ref"),
+        }
+    }
+
+    fn check_object(
+        &self,
+        fields: &HashMap<String, FieldSpec>,
+        value: &Value,
+        path: &str,
+        strict: bool,
+    ) -> Result<()> {
+        let map = value.as_object().ok_or_else(|| {
+            AdfError::validation_error(path, format!("expected object, found {}", Self::type_name(value)))
+        })?;
+
+        for (name, spec) in fields {
+            let field_path = Document::append_path(path, name);
+            match map.get(name) {
+                Some(child) => self.check(&spec.definition, child, &field_path, strict)?,
+                None if spec.required => {
+                    return Err(AdfError::validation_error(
+                        field_path,
+                        "required field is missing",
+                    ))
+                }
+                None => {}
+            }
+        }
+
+        if strict {
+            for key in map.keys() {
+                if !fields.contains_key(key) {
+                    return Err(AdfError::validation_error(
+                        Document::append_path(path, key),
+                        "unexpected key not declared in schema",
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_array(&self, element: &Definition, value: &Value, path: &str, strict: bool) -> Result<()> {
+        let items = value.as_array().ok_or_else(|| {
+            AdfError::validation_error(path, format!("expected array, found {}", Self::type_name(value)))
+        })?;
+
+        for (index, item) in items.iter().enumerate() {
+            self.check(element, item, &format!("{}[{}]", path, index), strict)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_union(
+        &self,
+        alternatives: &[Definition],
+        value: &Value,
+        path: &str,
+        strict: bool,
+    ) -> Result<()> {
+        for alternative in alternatives {
+            if self.check(alternative, value, path, strict).is_ok() {
+                return Ok(());
+            }
+        }
+        Err(AdfError::validation_error(
+            path,
+            "value did not match any alternative in the union",
+        ))
+    }
+
+    fn type_name(value: &Value) -> &'static str {
+        match value {
+            Value::String(_) => "string",
+            Value::Integer(_) => "integer",
+            Value::Float(_) => "float",
+            #[cfg(feature = "bignum")]
+            Value::BigInt(_) => "bigint",
+            #[cfg(feature = "bignum")]
+            Value::Decimal(_) => "decimal",
+            Value::Boolean(_) => "boolean",
+            Value::Null => "null",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+}
@@ -0,0 +1,284 @@
+//! A small path-selector query engine over [`crate::Value`] trees, used by
+//! [`crate::Document::select`].
+//!
+//! Selectors are dot-separated segments: a plain name (`server.host`), `*`
+//! to match every key/index at a level, `**` for recursive descent, `[n]`
+//! for array indexing, and bracketed predicates (`[age>10]`, `[name="Alice"]`)
+//! that filter an array's elements by comparing a child field.
+
+use crate::document::Document;
+use crate::error::{AdfError, Result};
+use crate::value::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Field(String),
+    AnyField,
+    RecursiveDescent,
+    Index(usize),
+    Predicate {
+        path: String,
+        op: CompareOp,
+        literal: Literal,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Literal {
+    String(String),
+    Number(f64),
+}
+
+/// Evaluate `selector` against `document`, returning a clone of every
+/// matching node.
+pub fn select(document: &Document, selector: &str) -> Result<Vec<Value>> {
+    let steps = compile(selector)?;
+    let root = Value::Object(document.as_map().clone());
+    let mut working: Vec<&Value> = vec![&root];
+
+    for step in &steps {
+        working = apply_step(step, &working);
+    }
+
+    Ok(working.into_iter().cloned().collect())
+}
+
+/// Parse a selector string into the sequence of steps that evaluate it.
+fn compile(selector: &str) -> Result<Vec<Step>> {
+    let mut steps = Vec::new();
+    for segment in split_top_level(selector) {
+        steps.extend(parse_segment(segment)?);
+    }
+    Ok(steps)
+}
+
+/// Split `selector` on top-level `.` characters, ignoring any inside `[...]`.
+fn split_top_level(selector: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (i, c) in selector.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '.' if depth == 0 => {
+                parts.push(&selector[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&selector[start..]);
+    parts
+}
+
+/// Parse one dot-separated segment, e.g. `users`, `*`, `**`, or
+/// `users[age>10]`, into its step(s).
+fn parse_segment(segment: &str) -> Result<Vec<Step>> {
+    let mut steps = Vec::new();
+
+    let bracket_pos = segment.find('[');
+    let (head, mut rest) = match bracket_pos {
+        Some(pos) => (&segment[..pos], &segment[pos..]),
+        None => (segment, ""),
+    };
+
+    match head {
+        "" => {}
+        "*" => steps.push(Step::AnyField),
+        "**" => steps.push(Step::RecursiveDescent),
+        name => steps.push(Step::Field(name.to_string())),
+    }
+
+    while !rest.is_empty() {
+        if !rest.starts_with('[') {
+            return Err(AdfError::Other(format!(
+                "unexpected trailing characters after ']' in selector segment '{}'",
+                segment
+            )));
+        }
+        let close = rest.find(']').ok_or_else(|| {
+            AdfError::Other(format!("unterminated '[' in selector segment '{}'", segment))
+        })?;
+        steps.push(parse_bracket(&rest[1..close], segment)?);
+        rest = &rest[close + 1..];
+    }
+
+    Ok(steps)
+}
+
+fn parse_bracket(inner: &str, segment: &str) -> Result<Step> {
+    if !inner.is_empty() && inner.chars().all(|c| c.is_ascii_digit()) {
+        let n: usize = inner.parse().map_err(|_| {
+            AdfError::Other(format!("invalid index '[{}]' in selector segment '{}'", inner, segment))
+        })?;
+        return Ok(Step::Index(n));
+    }
+
+    const OPERATORS: [(&str, CompareOp); 6] = [
+        (">=", CompareOp::Ge),
+        ("<=", CompareOp::Le),
+        ("!=", CompareOp::Ne),
+        ("=", CompareOp::Eq),
+        (">", CompareOp::Gt),
+        ("<", CompareOp::Lt),
+    ];
+
+    for (token, op) in OPERATORS {
+        if let Some(pos) = inner.find(token) {
+            let path = inner[..pos].trim();
+            if path.is_empty() {
+                return Err(AdfError::Other(format!(
+                    "predicate '[{}]' is missing a field path",
+                    inner
+                )));
+            }
+            let literal = parse_literal(inner[pos + token.len()..].trim());
+            return Ok(Step::Predicate {
+                path: path.to_string(),
+                op,
+                literal,
+            });
+        }
+    }
+
+    Err(AdfError::Other(format!(
+        "unrecognized selector predicate '[{}]'",
+        inner
+    )))
+}
+
+fn parse_literal(s: &str) -> Literal {
+    if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
+        return Literal::String(s[1..s.len() - 1].to_string());
+    }
+    match s.parse::<f64>() {
+        Ok(n) => Literal::Number(n),
+        Err(_) => Literal::String(s.to_string()),
+    }
+}
+
+fn apply_step<'a>(step: &Step, working: &[&'a Value]) -> Vec<&'a Value> {
+    let mut next = Vec::new();
+
+    match step {
+        Step::Field(name) => {
+            for value in working {
+                if let Value::Object(map) = value {
+                    if let Some(child) = map.get(name) {
+                        next.push(child);
+                    }
+                }
+            }
+        }
+        Step::AnyField => {
+            for value in working {
+                match value {
+                    Value::Object(map) => next.extend(map.values()),
+                    Value::Array(items) => next.extend(items.iter()),
+                    _ => {}
+                }
+            }
+        }
+        Step::RecursiveDescent => {
+            for value in working {
+                collect_descendants(value, &mut next);
+            }
+        }
+        Step::Index(n) => {
+            for value in working {
+                if let Value::Array(items) = value {
+                    if let Some(item) = items.get(*n) {
+                        next.push(item);
+                    }
+                }
+            }
+        }
+        Step::Predicate { path, op, literal } => {
+            for value in working {
+                if let Value::Array(items) = value {
+                    for item in items {
+                        if predicate_matches(item, path, *op, literal) {
+                            next.push(item);
+                        }
+                    }
+                } else if predicate_matches(value, path, *op, literal) {
+                    next.push(value);
+                }
+            }
+        }
+    }
+
+    next
+}
+
+/// Push `value` and every value nested within it (recursively) onto `out`.
+fn collect_descendants<'a>(value: &'a Value, out: &mut Vec<&'a Value>) {
+    out.push(value);
+    match value {
+        Value::Object(map) => {
+            for child in map.values() {
+                collect_descendants(child, out);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_descendants(item, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn predicate_matches(value: &Value, path: &str, op: CompareOp, literal: &Literal) -> bool {
+    let child = match get_path(value, path) {
+        Some(child) => child,
+        None => return false,
+    };
+
+    match op {
+        CompareOp::Eq => values_equal(child, literal),
+        CompareOp::Ne => !values_equal(child, literal),
+        CompareOp::Gt => compare_numeric(child, literal, |a, b| a > b),
+        CompareOp::Lt => compare_numeric(child, literal, |a, b| a < b),
+        CompareOp::Ge => compare_numeric(child, literal, |a, b| a >= b),
+        CompareOp::Le => compare_numeric(child, literal, |a, b| a <= b),
+    }
+}
+
+/// Walk a dot-notation path from `value` through nested objects.
+fn get_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut current = value;
+    for part in path.split('.') {
+        match current {
+            Value::Object(map) => current = map.get(part)?,
+            _ => return None,
+        }
+    }
+    Some(current)
+}
+
+fn values_equal(value: &Value, literal: &Literal) -> bool {
+    match literal {
+        Literal::String(s) => value.as_str() == Some(s.as_str()),
+        Literal::Number(n) => value.as_f64() == Some(*n),
+    }
+}
+
+fn compare_numeric(value: &Value, literal: &Literal, cmp: impl Fn(f64, f64) -> bool) -> bool {
+    match literal {
+        Literal::Number(n) => value.as_f64().map(|v| cmp(v, *n)).unwrap_or(false),
+        Literal::String(_) => false,
+    }
+}
@@ -1,16 +1,38 @@
 use std::collections::HashMap;
 
-#[cfg(feature = "serde")]
+// `num-bigint`/`bigdecimal` only implement `Serialize`/`Deserialize` when
+// built with their own `serde` feature, which this crate cannot force on as a
+// transitive dependency feature. Rather than leave the `serde` + `bignum`
+// combination uncompilable, `Value` only derives serde support when `bignum`
+// is off.
+#[cfg(all(feature = "serde", not(feature = "bignum")))]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "bignum")]
+use bigdecimal::BigDecimal;
+#[cfg(feature = "bignum")]
+use num_bigint::BigInt;
+#[cfg(feature = "bignum")]
+use num_traits::ToPrimitive;
+
 /// Represents a value in an ADF document
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(untagged))]
+#[cfg_attr(all(feature = "serde", not(feature = "bignum")), derive(Serialize, Deserialize))]
+#[cfg_attr(all(feature = "serde", not(feature = "bignum")), serde(untagged))]
 pub enum Value {
     String(String),
     Integer(i64),
     Float(f64),
+    /// An integer that overflowed `i64`, preserved at full precision.
+    /// Produced by type inference while parsing when the `bignum` feature
+    /// is enabled.
+    #[cfg(feature = "bignum")]
+    BigInt(BigInt),
+    /// A decimal value (e.g. a monetary amount) preserved exactly, rather
+    /// than rounded through `f64`. Only produced when the `bignum` feature
+    /// is enabled.
+    #[cfg(feature = "bignum")]
+    Decimal(BigDecimal),
     Boolean(bool),
     Null,
     Array(Vec<Value>),
@@ -33,6 +55,18 @@ impl Value {
         matches!(self, Value::Float(_))
     }
 
+    /// Check if value is an arbitrary-precision integer
+    #[cfg(feature = "bignum")]
+    pub fn is_bigint(&self) -> bool {
+        matches!(self, Value::BigInt(_))
+    }
+
+    /// Check if value is an arbitrary-precision decimal
+    #[cfg(feature = "bignum")]
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Value::Decimal(_))
+    }
+
     /// Check if value is a boolean
     pub fn is_boolean(&self) -> bool {
         matches!(self, Value::Boolean(_))
@@ -61,10 +95,13 @@ impl Value {
         }
     }
 
-    /// Get value as i64
+    /// Get value as i64. A [`Value::BigInt`] is promoted when it fits in
+    /// range; one that overflows `i64` returns `None` rather than truncating.
     pub fn as_i64(&self) -> Option<i64> {
         match self {
             Value::Integer(i) => Some(*i),
+            #[cfg(feature = "bignum")]
+            Value::BigInt(b) => b.to_i64(),
             _ => None,
         }
     }
@@ -74,6 +111,28 @@ impl Value {
         match self {
             Value::Float(f) => Some(*f),
             Value::Integer(i) => Some(*i as f64),
+            #[cfg(feature = "bignum")]
+            Value::BigInt(b) => b.to_f64(),
+            #[cfg(feature = "bignum")]
+            Value::Decimal(d) => d.to_f64(),
+            _ => None,
+        }
+    }
+
+    /// Get value as an arbitrary-precision integer reference
+    #[cfg(feature = "bignum")]
+    pub fn as_bigint(&self) -> Option<&BigInt> {
+        match self {
+            Value::BigInt(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Get value as an arbitrary-precision decimal reference
+    #[cfg(feature = "bignum")]
+    pub fn as_decimal(&self) -> Option<&BigDecimal> {
+        match self {
+            Value::Decimal(d) => Some(d),
             _ => None,
         }
     }
@@ -108,6 +167,10 @@ impl Value {
             Value::String(s) => Some(s.clone()),
             Value::Integer(i) => Some(i.to_string()),
             Value::Float(f) => Some(f.to_string()),
+            #[cfg(feature = "bignum")]
+            Value::BigInt(b) => Some(b.to_string()),
+            #[cfg(feature = "bignum")]
+            Value::Decimal(d) => Some(d.to_string()),
             Value::Boolean(b) => Some(b.to_string()),
             _ => None,
         }
@@ -138,6 +201,20 @@ impl From<f64> for Value {
     }
 }
 
+#[cfg(feature = "bignum")]
+impl From<BigInt> for Value {
+    fn from(b: BigInt) -> Self {
+        Value::BigInt(b)
+    }
+}
+
+#[cfg(feature = "bignum")]
+impl From<BigDecimal> for Value {
+    fn from(d: BigDecimal) -> Self {
+        Value::Decimal(d)
+    }
+}
+
 impl From<bool> for Value {
     fn from(b: bool) -> Self {
         Value::Boolean(b)
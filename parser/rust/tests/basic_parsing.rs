@@ -203,7 +203,7 @@ host.backup = backup.example.com
 }
 
 #[test]
-#[cfg(feature = "serde")]
+#[cfg(all(feature = "serde", not(feature = "bignum")))]
 fn test_to_json() {
     let text = r#"
 # person:
@@ -0,0 +1,56 @@
+#![cfg(feature = "bignum")]
+
+use adf::parse;
+use std::str::FromStr;
+
+#[test]
+fn test_overflowing_integer_becomes_bigint() {
+    let doc = parse("#:\ncount = 123456789012345678901234567890\n").unwrap();
+    let value = doc.get("count").unwrap();
+    assert!(value.is_bigint());
+    assert_eq!(
+        value.as_bigint().unwrap(),
+        &num_bigint::BigInt::from_str("123456789012345678901234567890").unwrap()
+    );
+}
+
+#[test]
+fn test_small_integer_still_becomes_integer() {
+    let doc = parse("#:\ncount = 42\n").unwrap();
+    assert!(doc.get("count").unwrap().is_integer());
+}
+
+#[test]
+fn test_decimal_is_preserved_exactly_as_decimal() {
+    let doc = parse("#:\nprice = 19.99\n").unwrap();
+    let value = doc.get("price").unwrap();
+    assert!(value.is_decimal());
+    assert_eq!(value.as_decimal().unwrap().to_string(), "19.99");
+}
+
+#[test]
+fn test_decimal_not_rounded_through_f64() {
+    let doc = parse("#:\namount = 0.1\n").unwrap();
+    let value = doc.get("amount").unwrap();
+    assert_eq!(value.as_decimal().unwrap().to_string(), "0.1");
+}
+
+#[test]
+fn test_scientific_notation_still_becomes_float() {
+    let doc = parse("#:\nratio = 1.5e3\n").unwrap();
+    assert!(doc.get("ratio").unwrap().is_float());
+}
+
+#[test]
+fn test_as_i64_promotes_bigint_within_range() {
+    let doc = parse("#:\ncount = 42\n").unwrap();
+    let bigint_value = adf::Value::from(num_bigint::BigInt::from(42));
+    assert_eq!(bigint_value.as_i64(), Some(42));
+    assert_eq!(doc.get("count").unwrap().as_i64(), Some(42));
+}
+
+#[test]
+fn test_as_i64_none_for_bigint_out_of_range() {
+    let doc = parse("#:\ncount = 123456789012345678901234567890\n").unwrap();
+    assert_eq!(doc.get("count").unwrap().as_i64(), None);
+}
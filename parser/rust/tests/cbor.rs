@@ -0,0 +1,31 @@
+#![cfg(feature = "cbor")]
+
+use adf::{parse, Document};
+
+#[test]
+fn test_cbor_round_trip() {
+    let text = r#"
+# person:
+name = Matthew
+age = 54
+active = true
+"#;
+
+    let doc = parse(text).unwrap();
+    let bytes = doc.to_cbor().unwrap();
+    let decoded = Document::from_cbor(&bytes).unwrap();
+    assert_eq!(doc, decoded);
+}
+
+#[test]
+fn test_cbor_is_deterministic_regardless_of_map_insertion_order() {
+    let a = parse("# a:\nx = 1\ny = 2\n").unwrap();
+    let b = parse("# a:\ny = 2\nx = 1\n").unwrap();
+    assert_eq!(a.to_cbor().unwrap(), b.to_cbor().unwrap());
+}
+
+#[test]
+fn test_cbor_rejects_malformed_input() {
+    let err = Document::from_cbor(&[0xff]).unwrap_err();
+    let _ = err.to_string();
+}
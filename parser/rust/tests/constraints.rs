@@ -0,0 +1,109 @@
+use adf::{parse_with_options, AdfError, ParseOptions};
+
+fn validating() -> ParseOptions {
+    ParseOptions {
+        validate_constraints: true,
+        ..ParseOptions::default()
+    }
+}
+
+#[test]
+fn test_type_constraint_passes() {
+    let text = r#"
+# person:
+age = 54 (int)
+"#;
+
+    let doc = parse_with_options(text, validating()).unwrap();
+    assert_eq!(doc.get("person.age").unwrap().as_i64().unwrap(), 54);
+}
+
+#[test]
+fn test_type_constraint_violation() {
+    let text = r#"
+# person:
+age = fifty-four (int)
+"#;
+
+    let err = parse_with_options(text, validating()).unwrap_err();
+    assert!(matches!(err, AdfError::PushingInvalidType { .. }));
+}
+
+#[test]
+fn test_range_constraint_violation() {
+    let text = r#"
+# person:
+age = 999 (range 0..120)
+"#;
+
+    let err = parse_with_options(text, validating()).unwrap_err();
+    assert!(matches!(err, AdfError::RangeViolation { .. }));
+}
+
+#[test]
+fn test_enum_constraint_violation() {
+    let text = r#"
+# person:
+role = wizard (enum admin|user|guest)
+"#;
+
+    let err = parse_with_options(text, validating()).unwrap_err();
+    assert!(matches!(err, AdfError::EnumViolation { .. }));
+}
+
+#[test]
+fn test_len_constraint_on_array_size() {
+    let text = r#"
+# hobbies:
+reading (len 1..2)
+physics
+coding
+"#;
+
+    let err = parse_with_options(text, validating()).unwrap_err();
+    assert!(matches!(err, AdfError::IndexOutOfRange { .. }));
+}
+
+#[test]
+fn test_unsatisfiable_constraint_is_config_error() {
+    let text = r#"
+# person:
+age = 54 (range 5..1)
+"#;
+
+    let err = parse_with_options(text, validating()).unwrap_err();
+    assert!(matches!(err, AdfError::InvalidConstraint { .. }));
+}
+
+#[test]
+fn test_required_constraint_rejects_empty_value() {
+    let text = r#"
+# person:
+name =  (required)
+"#;
+
+    let err = parse_with_options(text, validating()).unwrap_err();
+    assert!(matches!(err, AdfError::ValidationError { .. }));
+}
+
+#[test]
+fn test_required_constraint_passes_when_present() {
+    let text = r#"
+# person:
+name = Matthew (required)
+"#;
+
+    let doc = parse_with_options(text, validating()).unwrap();
+    assert_eq!(doc.get("person.name").unwrap().as_str().unwrap(), "Matthew");
+}
+
+#[test]
+fn test_constraints_ignored_when_not_validating() {
+    let text = r#"
+# person:
+age = fifty-four (int)
+"#;
+
+    let doc = adf::parse(text).unwrap();
+    assert_eq!(doc.get("person.age").unwrap().as_str().unwrap(), "fifty-four");
+}
@@ -0,0 +1,56 @@
+use adf::{parse, AdfError};
+
+#[test]
+fn test_malformed_absolute_header_reports_span() {
+    let text = r#"
+# inv@lid:
+name = Matthew
+"#;
+
+    let err = parse(text).unwrap_err();
+    match err {
+        AdfError::ParseError { line, .. } => assert_eq!(line, 2),
+        other => panic!("expected ParseError, got {:?}", other),
+    }
+    let span = err.span().expect("malformed header should carry a span");
+    assert_eq!(span.line, 2);
+}
+
+#[test]
+fn test_unterminated_multiline_is_an_error() {
+    let text = r#"
+# article:
+body = """
+This is never closed.
+"#;
+
+    let err = parse(text).unwrap_err();
+    assert!(matches!(err, AdfError::ParseError { .. }));
+}
+
+#[test]
+fn test_render_includes_caret_snippet() {
+    let text = "# inv@lid:\nname = Matthew\n";
+    let err = parse(text).unwrap_err();
+    let rendered = err.render(text);
+    assert!(rendered.contains('^'));
+}
+
+#[test]
+fn test_constraint_violation_reports_span() {
+    use adf::{parse_with_options, ParseOptions};
+
+    let text = r#"
+# person:
+age = fifty-four (int)
+"#;
+
+    let options = ParseOptions {
+        validate_constraints: true,
+        ..ParseOptions::default()
+    };
+    let err = parse_with_options(text, options).unwrap_err();
+    assert!(matches!(err, AdfError::PushingInvalidType { .. }));
+    let span = err.span().expect("constraint violation should carry a span");
+    assert_eq!(span.line, 3);
+}
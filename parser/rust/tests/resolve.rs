@@ -0,0 +1,109 @@
+use adf::{parse, resolve};
+use std::fs;
+use std::path::PathBuf;
+
+/// Create a fresh scratch directory under the system temp dir for one test,
+/// so concurrent test runs don't collide on the same files.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("adf_resolve_test_{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_resolve_merges_single_import() {
+    let dir = scratch_dir("single_import");
+    fs::write(
+        dir.join("defaults.adf"),
+        "# server:\nhost = localhost\nport = 8080\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("main.adf"),
+        "@import \"defaults.adf\"\n\n# server:\nport = 9090\n",
+    )
+    .unwrap();
+
+    let text = fs::read_to_string(dir.join("main.adf")).unwrap();
+    let doc = resolve(parse(&text).unwrap(), &dir).unwrap();
+
+    assert_eq!(doc.get("server.host").unwrap().as_str().unwrap(), "localhost");
+    assert_eq!(doc.get("server.port").unwrap().as_i64().unwrap(), 9090);
+}
+
+#[test]
+fn test_resolve_with_no_imports_is_a_no_op() {
+    let dir = scratch_dir("no_imports");
+    let doc = resolve(parse("#:\nname = ADF\n").unwrap(), &dir).unwrap();
+    assert_eq!(doc.get("name").unwrap().as_str().unwrap(), "ADF");
+}
+
+#[test]
+fn test_resolve_follows_transitive_imports() {
+    let dir = scratch_dir("transitive");
+    fs::write(dir.join("base.adf"), "#:\nlevel = base\n").unwrap();
+    fs::write(
+        dir.join("middle.adf"),
+        "@import \"base.adf\"\n\n#:\ntier = middle\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("main.adf"),
+        "@import \"middle.adf\"\n\n#:\nname = app\n",
+    )
+    .unwrap();
+
+    let text = fs::read_to_string(dir.join("main.adf")).unwrap();
+    let doc = resolve(parse(&text).unwrap(), &dir).unwrap();
+
+    assert_eq!(doc.get("level").unwrap().as_str().unwrap(), "base");
+    assert_eq!(doc.get("tier").unwrap().as_str().unwrap(), "middle");
+    assert_eq!(doc.get("name").unwrap().as_str().unwrap(), "app");
+}
+
+#[test]
+fn test_resolve_imports_from_subdirectory() {
+    let dir = scratch_dir("subdir");
+    fs::create_dir_all(dir.join("fragments")).unwrap();
+    fs::write(
+        dir.join("fragments").join("extra.adf"),
+        "#:\nfeature = enabled\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("main.adf"),
+        "@import \"fragments/extra.adf\"\n\n#:\nname = app\n",
+    )
+    .unwrap();
+
+    let text = fs::read_to_string(dir.join("main.adf")).unwrap();
+    let doc = resolve(parse(&text).unwrap(), &dir).unwrap();
+
+    assert_eq!(doc.get("feature").unwrap().as_str().unwrap(), "enabled");
+}
+
+#[test]
+fn test_resolve_detects_direct_cycle() {
+    let dir = scratch_dir("direct_cycle");
+    fs::write(dir.join("a.adf"), "@import \"b.adf\"\n\n#:\nfrom_a = true\n").unwrap();
+    fs::write(dir.join("b.adf"), "@import \"a.adf\"\n\n#:\nfrom_b = true\n").unwrap();
+
+    let text = fs::read_to_string(dir.join("a.adf")).unwrap();
+    let err = resolve(parse(&text).unwrap(), &dir).unwrap_err();
+
+    match err {
+        adf::AdfError::Other(message) => assert!(message.starts_with("import cycle:")),
+        other => panic!("expected AdfError::Other, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_resolve_fails_on_missing_import() {
+    let dir = scratch_dir("missing_import");
+    fs::write(dir.join("main.adf"), "@import \"missing.adf\"\n").unwrap();
+
+    let text = fs::read_to_string(dir.join("main.adf")).unwrap();
+    let err = resolve(parse(&text).unwrap(), &dir).unwrap_err();
+    assert!(matches!(err, adf::AdfError::IoError(_)));
+}
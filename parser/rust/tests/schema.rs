@@ -0,0 +1,104 @@
+use adf::{parse, AdfError, Definition, FieldSpec, ScalarType, Schema};
+use std::collections::HashMap;
+
+fn person_schema() -> Schema {
+    let mut fields = HashMap::new();
+    fields.insert("name".to_string(), FieldSpec::required(Definition::Scalar(ScalarType::String)));
+    fields.insert("age".to_string(), FieldSpec::required(Definition::Scalar(ScalarType::Integer)));
+    fields.insert(
+        "nickname".to_string(),
+        FieldSpec::optional(Definition::Scalar(ScalarType::String)),
+    );
+
+    let mut root_fields = HashMap::new();
+    root_fields.insert("person".to_string(), FieldSpec::required(Definition::Object(fields)));
+    Schema::new(Definition::Object(root_fields))
+}
+
+#[test]
+fn test_schema_accepts_matching_document() {
+    let doc = parse("# person:\nname = Matthew\nage = 54\n").unwrap();
+    person_schema().validate(&doc).unwrap();
+}
+
+#[test]
+fn test_schema_rejects_missing_required_field() {
+    let doc = parse("# person:\nname = Matthew\n").unwrap();
+    let err = person_schema().validate(&doc).unwrap_err();
+    match err {
+        AdfError::ValidationError { path, .. } => assert_eq!(path, "person.age"),
+        other => panic!("expected ValidationError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_schema_rejects_wrong_type() {
+    let doc = parse("# person:\nname = Matthew\nage = fiftyfour\n").unwrap();
+    let err = person_schema().validate(&doc).unwrap_err();
+    assert!(matches!(err, AdfError::ValidationError { .. }));
+}
+
+#[test]
+fn test_lenient_mode_ignores_unknown_keys() {
+    let doc = parse("# person:\nname = Matthew\nage = 54\ncity = Fayetteville\n").unwrap();
+    person_schema().validate(&doc).unwrap();
+}
+
+#[test]
+fn test_strict_mode_rejects_unknown_keys() {
+    let doc = parse("# person:\nname = Matthew\nage = 54\ncity = Fayetteville\n").unwrap();
+    let err = person_schema().validate_strict(&doc).unwrap_err();
+    match err {
+        AdfError::ValidationError { path, .. } => assert_eq!(path, "person.city"),
+        other => panic!("expected ValidationError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_array_of_and_ref_definitions() {
+    let mut fields = HashMap::new();
+    fields.insert("name".to_string(), FieldSpec::required(Definition::Scalar(ScalarType::String)));
+    fields.insert("age".to_string(), FieldSpec::required(Definition::Scalar(ScalarType::Integer)));
+
+    let mut root_fields = HashMap::new();
+    root_fields.insert(
+        "users".to_string(),
+        FieldSpec::required(Definition::ArrayOf(Box::new(Definition::Ref("user".to_string())))),
+    );
+
+    let schema = Schema::new(Definition::Object(root_fields))
+        .define("user", Definition::Object(fields));
+
+    let doc = parse(
+        r#"
+# users:
+
+name = Alice
+age = 22
+
+name = Bob
+age = 30
+"#,
+    )
+    .unwrap();
+
+    schema.validate(&doc).unwrap();
+}
+
+#[test]
+fn test_union_definition() {
+    let mut fields = HashMap::new();
+    fields.insert(
+        "id".to_string(),
+        FieldSpec::required(Definition::Union(vec![
+            Definition::Scalar(ScalarType::String),
+            Definition::Scalar(ScalarType::Integer),
+        ])),
+    );
+    let schema = Schema::new(Definition::Object(fields));
+
+    let numeric = parse("#:\nid = 7\n").unwrap();
+    let stringy = parse("#:\nid = seven\n").unwrap();
+    schema.validate(&numeric).unwrap();
+    schema.validate(&stringy).unwrap();
+}
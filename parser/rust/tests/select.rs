@@ -0,0 +1,101 @@
+use adf::parse;
+
+fn users_doc() -> adf::Document {
+    parse(
+        r#"
+# users:
+
+name = Alice
+age = 22
+
+name = Bob
+age = 30
+
+name = Carol
+age = 41
+"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_select_simple_field_path() {
+    let doc = parse("# person:\nname = Matthew\nage = 54\n").unwrap();
+    let results = doc.select("person.name").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].as_str().unwrap(), "Matthew");
+}
+
+#[test]
+fn test_select_any_field_matches_every_key() {
+    let doc = parse("#:\na = 1\nb = 2\nc = 3\n").unwrap();
+    let mut results: Vec<i64> = doc.select("*").unwrap().iter().map(|v| v.as_i64().unwrap()).collect();
+    results.sort();
+    assert_eq!(results, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_select_array_index() {
+    let doc = users_doc();
+    let results = doc.select("users[0].name").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].as_str().unwrap(), "Alice");
+}
+
+#[test]
+fn test_select_predicate_equality() {
+    let doc = users_doc();
+    let results = doc.select("users[name=\"Bob\"]").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].as_object().unwrap().get("age").unwrap().as_i64().unwrap(),
+        30
+    );
+}
+
+#[test]
+fn test_select_predicate_numeric_comparison() {
+    let doc = users_doc();
+    let mut names: Vec<String> = doc
+        .select("users[age>25]")
+        .unwrap()
+        .iter()
+        .map(|v| v.as_object().unwrap().get("name").unwrap().as_str().unwrap().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["Bob".to_string(), "Carol".to_string()]);
+}
+
+#[test]
+fn test_select_any_field_over_array_then_field() {
+    let doc = users_doc();
+    let mut names: Vec<String> = doc
+        .select("users.*.name")
+        .unwrap()
+        .iter()
+        .map(|v| v.as_str().unwrap().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()]);
+}
+
+#[test]
+fn test_select_recursive_descent_finds_nested_values() {
+    let doc = parse("# a.b.c:\nvalue = 42\n").unwrap();
+    let results = doc.select("**").unwrap();
+    assert!(results.iter().any(|v| v.as_i64() == Some(42)));
+}
+
+#[test]
+fn test_select_no_match_returns_empty() {
+    let doc = users_doc();
+    let results = doc.select("users[name=\"Nobody\"]").unwrap();
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_select_rejects_malformed_selector() {
+    let doc = users_doc();
+    assert!(doc.select("users[").is_err());
+    assert!(doc.select("users[nonsense]").is_err());
+}
@@ -0,0 +1,104 @@
+use adf::{parse, SerializeOptions};
+
+#[test]
+fn test_round_trip_simple_object() {
+    let text = r#"
+# person:
+name = Matthew
+age = 54
+"#;
+
+    let doc = parse(text).unwrap();
+    let rendered = doc.to_adf();
+    let reparsed = parse(&rendered).unwrap();
+    assert_eq!(doc, reparsed);
+}
+
+#[test]
+fn test_round_trip_nested_object() {
+    let text = r#"
+# person.address:
+city = Fayetteville
+state = NY
+"#;
+
+    let doc = parse(text).unwrap();
+    let reparsed = parse(&doc.to_adf()).unwrap();
+    assert_eq!(doc, reparsed);
+}
+
+#[test]
+fn test_round_trip_scalar_array() {
+    let text = r#"
+# hobbies:
+reading
+physics
+coding
+"#;
+
+    let doc = parse(text).unwrap();
+    let reparsed = parse(&doc.to_adf()).unwrap();
+    assert_eq!(doc, reparsed);
+}
+
+#[test]
+fn test_round_trip_object_array() {
+    let text = r#"
+# users:
+
+name = Alice
+age = 22
+
+name = Bob
+age = 30
+"#;
+
+    let doc = parse(text).unwrap();
+    let reparsed = parse(&doc.to_adf()).unwrap();
+    assert_eq!(doc, reparsed);
+}
+
+#[test]
+fn test_round_trip_multiline_value() {
+    let text = r#"
+# article:
+body = """
+This is line one.
+This is line two.
+"""
+"#;
+
+    let doc = parse(text).unwrap();
+    let reparsed = parse(&doc.to_adf()).unwrap();
+    assert_eq!(doc, reparsed);
+}
+
+#[test]
+fn test_round_trip_integral_float() {
+    let text = r#"
+# invoice:
+amount = 2.5e3
+"#;
+
+    let doc = parse(text).unwrap();
+    assert!(doc.get("invoice.amount").unwrap().is_float());
+    let reparsed = parse(&doc.to_adf()).unwrap();
+    assert_eq!(doc, reparsed);
+}
+
+#[test]
+fn test_relative_header_style_is_opt_in() {
+    let text = r#"
+# person.address:
+city = Fayetteville
+"#;
+
+    let doc = parse(text).unwrap();
+    let options = SerializeOptions {
+        prefer_relative_headers: true,
+        ..SerializeOptions::default()
+    };
+    let rendered = doc.to_adf_with(&options);
+    assert!(rendered.contains("person.address:\n"));
+    assert!(!rendered.contains("# person.address:"));
+}